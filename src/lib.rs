@@ -36,6 +36,7 @@ mod test;
 
 use alloc::vec::Vec;
 use core::fmt;
+use core::ops::RangeBounds;
 
 const INLINE_BITS: usize = core::mem::size_of::<usize>() * 8;
 const INLINE_BITS_POT: usize = INLINE_BITS.trailing_zeros() as usize;
@@ -247,6 +248,240 @@ impl<const N: usize> IttyBitty<N> {
         core::mem::forget(v);
     }
 
+    /// Read the word at `word`, treating indices beyond the backing length as `0`.
+    #[inline]
+    fn word_or_zero(&self, word: usize) -> usize {
+        if word < self.words() {
+            unsafe { *self.get_word_unchecked(word) }
+        } else {
+            0
+        }
+    }
+
+    /// Number of words up to and including the highest nonzero word.
+    ///
+    /// Trailing zero words are not significant: two values with the same
+    /// significant prefix represent the same set of bits.
+    #[inline]
+    fn significant_words(&self) -> usize {
+        let mut w = self.words();
+        while w > 0 && self.word_or_zero(w - 1) == 0 {
+            w -= 1;
+        }
+        w
+    }
+
+    /// Set every bit that is set in `self` or `other`.
+    ///
+    /// Grows to fit `other` when it reaches past `capacity()`.
+    ///
+    /// The in-place combinators are named with a `_with` suffix
+    /// ([`union_with`](Self::union_with), [`intersect_with`](Self::intersect_with),
+    /// [`difference_with`](Self::difference_with),
+    /// [`symmetric_difference_with`](Self::symmetric_difference_with)) so that the
+    /// unsuffixed [`intersection`](Self::intersection),
+    /// [`difference`](Self::difference), and
+    /// [`symmetric_difference`](Self::symmetric_difference) names are free for the
+    /// lazy index iterators. The [`BitOr`](core::ops::BitOr) family of operators
+    /// wraps these in-place methods.
+    pub fn union_with(&mut self, other: &Self) {
+        let words = other.significant_words();
+        if words > 0 {
+            let bits = (words << INLINE_BITS_POT)
+                - other.word_or_zero(words - 1).leading_zeros() as usize;
+            if bits > self.capacity() {
+                self.reallocate(bits);
+            }
+        }
+        for w in 0..self.words() {
+            unsafe {
+                *self.get_word_unchecked_mut(w) |= other.word_or_zero(w);
+            }
+        }
+    }
+
+    /// Keep only the bits that are set in both `self` and `other`.
+    ///
+    /// Never grows: bits beyond `other` are cleared.
+    pub fn intersect_with(&mut self, other: &Self) {
+        for w in 0..self.words() {
+            unsafe {
+                *self.get_word_unchecked_mut(w) &= other.word_or_zero(w);
+            }
+        }
+    }
+
+    /// Clear every bit that is set in `other`.
+    ///
+    /// Never grows.
+    pub fn difference_with(&mut self, other: &Self) {
+        for w in 0..self.words() {
+            unsafe {
+                *self.get_word_unchecked_mut(w) &= !other.word_or_zero(w);
+            }
+        }
+    }
+
+    /// Set every bit that is set in exactly one of `self` and `other`.
+    ///
+    /// Grows to fit `other` when it reaches past `capacity()`.
+    pub fn symmetric_difference_with(&mut self, other: &Self) {
+        let words = other.significant_words();
+        if words > 0 {
+            let bits = (words << INLINE_BITS_POT)
+                - other.word_or_zero(words - 1).leading_zeros() as usize;
+            if bits > self.capacity() {
+                self.reallocate(bits);
+            }
+        }
+        for w in 0..self.words() {
+            unsafe {
+                *self.get_word_unchecked_mut(w) ^= other.word_or_zero(w);
+            }
+        }
+    }
+
+    /// Iterate over the indices of bits set in both `self` and `other`,
+    /// without materializing a combined bitset.
+    #[inline]
+    pub fn intersection<'a>(&'a self, other: &'a IttyBitty<N>) -> Combine<'a, N> {
+        Combine::new(self, other, CombineOp::And)
+    }
+
+    /// Iterate over the indices of bits set in `self` but not `other`,
+    /// without materializing a combined bitset.
+    #[inline]
+    pub fn difference<'a>(&'a self, other: &'a IttyBitty<N>) -> Combine<'a, N> {
+        Combine::new(self, other, CombineOp::AndNot)
+    }
+
+    /// Iterate over the indices of bits set in exactly one of `self` and
+    /// `other`, without materializing a combined bitset.
+    #[inline]
+    pub fn symmetric_difference<'a>(&'a self, other: &'a IttyBitty<N>) -> Combine<'a, N> {
+        Combine::new(self, other, CombineOp::Xor)
+    }
+
+    /// Resolve a `RangeBounds` into a `[start, end)` bit range.
+    #[inline]
+    fn resolve_range(&self, range: impl RangeBounds<usize>) -> (usize, usize) {
+        use core::ops::Bound::*;
+        let start = match range.start_bound() {
+            Included(&s) => s,
+            Excluded(&s) => s + 1,
+            Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Included(&e) => e + 1,
+            Excluded(&e) => e,
+            Unbounded => self.capacity(),
+        };
+        (start, end)
+    }
+
+    /// Apply `op` word-at-a-time across `[start, end)`, passing the mask of
+    /// affected bits for each word. Does nothing if the range is empty.
+    fn for_range_words(&mut self, start: usize, end: usize, op: impl Fn(&mut usize, usize)) {
+        if start >= end {
+            return;
+        }
+        let start_word = start >> INLINE_BITS_POT;
+        let start_bit = start & INLINE_BITS_MASK;
+        let end_word = end >> INLINE_BITS_POT;
+        let end_bit = end & INLINE_BITS_MASK;
+
+        if start_word == end_word {
+            let mask = (!0 << start_bit) & !(!0 << end_bit);
+            op(unsafe { self.get_word_unchecked_mut(start_word) }, mask);
+            return;
+        }
+
+        op(unsafe { self.get_word_unchecked_mut(start_word) }, !0 << start_bit);
+        for w in (start_word + 1)..end_word {
+            op(unsafe { self.get_word_unchecked_mut(w) }, !0);
+        }
+        if end_bit != 0 {
+            op(unsafe { self.get_word_unchecked_mut(end_word) }, !(!0 << end_bit));
+        }
+    }
+
+    /// Set every bit in `range` to `value`.
+    ///
+    /// Grows to fit the range when `value` is `true`; when `false`, clamps to
+    /// the current capacity like [`set`](Self::set).
+    pub fn set_range(&mut self, range: impl RangeBounds<usize>, value: bool) {
+        let (start, mut end) = self.resolve_range(range);
+        if value {
+            if end > self.capacity() {
+                self.reallocate(end);
+            }
+            self.for_range_words(start, end, |w, mask| *w |= mask);
+        } else {
+            end = end.min(self.capacity());
+            self.for_range_words(start, end, |w, mask| *w &= !mask);
+        }
+    }
+
+    /// Set every bit in `range` to `true`, growing as needed.
+    #[inline]
+    pub fn insert_range(&mut self, range: impl RangeBounds<usize>) {
+        self.set_range(range, true);
+    }
+
+    /// Flip every bit in `range`, growing to fit the range as needed.
+    pub fn toggle_range(&mut self, range: impl RangeBounds<usize>) {
+        let (start, end) = self.resolve_range(range);
+        if end > self.capacity() {
+            self.reallocate(end);
+        }
+        self.for_range_words(start, end, |w, mask| *w ^= mask);
+    }
+
+    /// Count the number of set bits.
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        let mut count = 0;
+        for w in 0..self.words() {
+            count += unsafe { self.get_word_unchecked(w) }.count_ones() as usize;
+        }
+        count
+    }
+
+    /// Count the set bits strictly before `bit`.
+    pub fn rank(&self, bit: usize) -> usize {
+        let bit = bit.min(self.capacity());
+        let last = bit >> INLINE_BITS_POT;
+        let mut count = 0;
+        for w in 0..last {
+            count += unsafe { self.get_word_unchecked(w) }.count_ones() as usize;
+        }
+        let partial = bit & INLINE_BITS_MASK;
+        if partial != 0 {
+            let word = unsafe { self.get_word_unchecked(last) };
+            count += (word & !(!0 << partial)).count_ones() as usize;
+        }
+        count
+    }
+
+    /// Get the 0-based position of the `k`-th set bit.
+    ///
+    /// Returns `usize::MAX` if fewer than `k + 1` bits are set.
+    pub fn select(&self, k: usize) -> usize {
+        let mut prior = 0;
+        for w in 0..self.words() {
+            let mut word = unsafe { *self.get_word_unchecked(w) };
+            let ones = word.count_ones() as usize;
+            if prior + ones > k {
+                for _ in 0..(k - prior) {
+                    word &= word - 1;
+                }
+                return (w << INLINE_BITS_POT) + word.trailing_zeros() as usize;
+            }
+            prior += ones;
+        }
+        usize::MAX
+    }
+
     /// Iterate over true bits.
     #[inline]
     pub fn iter(&self) -> Iter<N> {
@@ -334,6 +569,31 @@ impl<const N: usize> Default for IttyBitty<N> {
     }
 }
 
+impl<const N: usize> Clone for IttyBitty<N> {
+    fn clone(&self) -> Self {
+        if self.spilled() {
+            let mut v = Vec::with_capacity(self.words());
+            v.extend_from_slice(self.buffer());
+            v.resize(v.capacity(), 0);
+            Self::from_vec(v)
+        } else {
+            Self { data: self.data }
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        if source.spilled() && self.spilled() && self.words() >= source.words() {
+            let significant = source.words();
+            let words = self.words();
+            let dst = self.buffer_mut();
+            dst[..significant].copy_from_slice(source.buffer());
+            dst[significant..words].fill(0);
+        } else {
+            *self = source.clone();
+        }
+    }
+}
+
 impl<const N: usize> fmt::Debug for IttyBitty<N> {
     #[inline]
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
@@ -343,33 +603,118 @@ impl<const N: usize> fmt::Debug for IttyBitty<N> {
 
 impl<const N: usize> PartialEq for IttyBitty<N> {
     fn eq(&self, other: &Self) -> bool {
-        let words_a = self.words();
-        let words_b = other.words();
-        if words_a > words_b {
-            for w in words_a..words_b {
-                if unsafe { *other.get_word_unchecked(w) } != 0 {
-                    return false;
-                }
-            }
+        let words = self.significant_words();
+        if words != other.significant_words() {
+            return false;
         }
-        if words_b > words_a {
-            for w in words_b..words_a {
-                if unsafe { *self.get_word_unchecked(w) } != 0 {
-                    return false;
-                }
-            }
-        }
-        for w in 0..words_a {
-            if unsafe { *self.get_word_unchecked(w) != *other.get_word_unchecked(w) } {
+        for w in 0..words {
+            if self.word_or_zero(w) != other.word_or_zero(w) {
                 return false;
             }
         }
-        return true;
+        true
     }
 }
 
 impl<const N: usize> Eq for IttyBitty<N> {}
 
+impl<const N: usize> core::ops::BitOr<&IttyBitty<N>> for IttyBitty<N> {
+    type Output = IttyBitty<N>;
+
+    #[inline]
+    fn bitor(mut self, rhs: &IttyBitty<N>) -> IttyBitty<N> {
+        self.union_with(rhs);
+        self
+    }
+}
+
+impl<const N: usize> core::ops::BitAnd<&IttyBitty<N>> for IttyBitty<N> {
+    type Output = IttyBitty<N>;
+
+    #[inline]
+    fn bitand(mut self, rhs: &IttyBitty<N>) -> IttyBitty<N> {
+        self.intersect_with(rhs);
+        self
+    }
+}
+
+impl<const N: usize> core::ops::Sub<&IttyBitty<N>> for IttyBitty<N> {
+    type Output = IttyBitty<N>;
+
+    #[inline]
+    fn sub(mut self, rhs: &IttyBitty<N>) -> IttyBitty<N> {
+        self.difference_with(rhs);
+        self
+    }
+}
+
+impl<const N: usize> core::ops::BitXor<&IttyBitty<N>> for IttyBitty<N> {
+    type Output = IttyBitty<N>;
+
+    #[inline]
+    fn bitxor(mut self, rhs: &IttyBitty<N>) -> IttyBitty<N> {
+        self.symmetric_difference_with(rhs);
+        self
+    }
+}
+
+impl<const N: usize> core::ops::BitOrAssign<&IttyBitty<N>> for IttyBitty<N> {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &IttyBitty<N>) {
+        self.union_with(rhs);
+    }
+}
+
+impl<const N: usize> core::ops::BitAndAssign<&IttyBitty<N>> for IttyBitty<N> {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &IttyBitty<N>) {
+        self.intersect_with(rhs);
+    }
+}
+
+impl<const N: usize> core::ops::SubAssign<&IttyBitty<N>> for IttyBitty<N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &IttyBitty<N>) {
+        self.difference_with(rhs);
+    }
+}
+
+impl<const N: usize> core::ops::BitXorAssign<&IttyBitty<N>> for IttyBitty<N> {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &IttyBitty<N>) {
+        self.symmetric_difference_with(rhs);
+    }
+}
+
+impl<const N: usize> core::hash::Hash for IttyBitty<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        for w in 0..self.significant_words() {
+            unsafe { self.get_word_unchecked(w) }.hash(state);
+        }
+    }
+}
+
+impl<const N: usize> Ord for IttyBitty<N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        use core::cmp::Ordering;
+        let words = self.significant_words().max(other.significant_words());
+        for w in (0..words).rev() {
+            match self.word_or_zero(w).cmp(&other.word_or_zero(w)) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl<const N: usize> PartialOrd for IttyBitty<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<const N: usize> IntoIterator for IttyBitty<N> {
     type Item = usize;
     type IntoIter = IntoIter<N>;
@@ -390,6 +735,52 @@ impl<'a, const N: usize> IntoIterator for &'a IttyBitty<N> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for IttyBitty<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(None)?;
+        for bit in self.iter() {
+            seq.serialize_element(&bit)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for IttyBitty<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct IttyBittyVisitor<const N: usize>;
+
+        impl<'de, const N: usize> serde::de::Visitor<'de> for IttyBittyVisitor<N> {
+            type Value = IttyBitty<N>;
+
+            fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.write_str("a sequence of set bit indices")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut v = IttyBitty::<N>::new();
+                while let Some(bit) = seq.next_element::<usize>()? {
+                    v.set(bit, true);
+                }
+                Ok(v)
+            }
+        }
+
+        deserializer.deserialize_seq(IttyBittyVisitor)
+    }
+}
+
 /// IttyBitty owned iterator
 #[derive(Debug)]
 pub struct IntoIter<const N: usize> {
@@ -434,6 +825,66 @@ impl<'a, const N: usize> Iterator for Iter<'a, N> {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+enum CombineOp {
+    And,
+    AndNot,
+    Xor,
+}
+
+/// Lazy iterator over the set bits of a word-wise combination of two
+/// [`IttyBitty`] values, as produced by [`IttyBitty::intersection`],
+/// [`IttyBitty::difference`], and [`IttyBitty::symmetric_difference`].
+#[derive(Debug)]
+pub struct Combine<'a, const N: usize> {
+    a: &'a IttyBitty<N>,
+    b: &'a IttyBitty<N>,
+    op: CombineOp,
+    words: usize,
+    word: usize,
+    bits: usize,
+}
+
+impl<'a, const N: usize> Combine<'a, N> {
+    #[inline]
+    fn new(a: &'a IttyBitty<N>, b: &'a IttyBitty<N>, op: CombineOp) -> Self {
+        Self {
+            a,
+            b,
+            op,
+            words: a.words().max(b.words()),
+            word: 0,
+            bits: 0,
+        }
+    }
+}
+
+impl<'a, const N: usize> Iterator for Combine<'a, N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            if self.bits != 0 {
+                let t = self.bits.trailing_zeros() as usize;
+                self.bits &= self.bits - 1;
+                return Some(((self.word - 1) << INLINE_BITS_POT) + t);
+            }
+            if self.word >= self.words {
+                return None;
+            }
+            let w = self.word;
+            self.word += 1;
+            let a = self.a.word_or_zero(w);
+            let b = self.b.word_or_zero(w);
+            self.bits = match self.op {
+                CombineOp::And => a & b,
+                CombineOp::AndNot => a & !b,
+                CombineOp::Xor => a ^ b,
+            };
+        }
+    }
+}
+
 /// IttyBitty reverse iterator
 #[derive(Debug)]
 pub struct IterRev<'a, const N: usize> {
@@ -204,6 +204,161 @@ mod tests {
         assert_eq!(b.prev_set_bit(1001), 1000);
     }
 
+    fn from_bits<const N: usize>(bits: &[usize]) -> IttyBitty<N> {
+        let mut b = IttyBitty::<N>::new();
+        for &bit in bits {
+            b.set(bit, true);
+        }
+        b
+    }
+
+    #[test]
+    fn test_set_algebra<const N: usize>() {
+        let a = || from_bits::<N>(&[1, 3, 200]);
+        let b = || from_bits::<N>(&[3, 4, 500]);
+
+        assert_eq!(
+            (a() | &b()).iter().collect::<Vec<_>>(),
+            [1, 3, 4, 200, 500]
+        );
+        assert_eq!((a() & &b()).iter().collect::<Vec<_>>(), [3]);
+        assert_eq!((a() - &b()).iter().collect::<Vec<_>>(), [1, 200]);
+        assert_eq!(
+            (a() ^ &b()).iter().collect::<Vec<_>>(),
+            [1, 4, 200, 500]
+        );
+
+        let mut x = a();
+        x &= &b();
+        assert_eq!(x.iter().collect::<Vec<_>>(), [3]);
+
+        let mut y = a();
+        y -= &b();
+        assert_eq!(y.iter().collect::<Vec<_>>(), [1, 200]);
+
+        let mut z = a();
+        z |= &b();
+        assert_eq!(z.iter().collect::<Vec<_>>(), [1, 3, 4, 200, 500]);
+
+        let mut w = a();
+        w ^= &b();
+        assert_eq!(w.iter().collect::<Vec<_>>(), [1, 4, 200, 500]);
+    }
+
+    #[test]
+    fn test_count_rank_select<const N: usize>() {
+        let bits = [1usize, 3, 63, 64, 200, 500];
+        let b = from_bits::<N>(&bits);
+
+        assert_eq!(b.count_ones(), bits.len());
+
+        assert_eq!(b.rank(0), 0);
+        assert_eq!(b.rank(2), 1);
+        assert_eq!(b.rank(4), 2);
+        assert_eq!(b.rank(64), 3);
+        assert_eq!(b.rank(65), 4);
+        assert_eq!(b.rank(1000), bits.len());
+
+        for (k, &bit) in bits.iter().enumerate() {
+            assert_eq!(b.select(k), bit);
+        }
+        assert_eq!(b.select(bits.len()), usize::MAX);
+    }
+
+    #[test]
+    fn test_clone<const N: usize>() {
+        let inline = from_bits::<N>(&[1, 3, 5]);
+        assert_eq!(inline.clone(), inline);
+
+        let spilled = from_bits::<N>(&[1, 200, 500]);
+        let copy = spilled.clone();
+        assert_eq!(copy, spilled);
+        // the clone owns its own buffer: mutating it must not touch the source
+        let mut copy = copy;
+        copy.set(200, false);
+        assert_eq!(spilled.get(200), true);
+        assert_eq!(copy.get(200), false);
+
+        let mut dst = from_bits::<N>(&[0, 700]);
+        dst.clone_from(&spilled);
+        assert_eq!(dst, spilled);
+    }
+
+    #[test]
+    fn test_set_range<const N: usize>() {
+        let mut b = IttyBitty::<N>::new();
+        b.insert_range(60..70);
+        assert_eq!(b.iter().collect::<Vec<_>>(), (60..70).collect::<Vec<_>>());
+
+        b.set_range(62..66, false);
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            [60, 61, 66, 67, 68, 69]
+        );
+
+        let mut c = IttyBitty::<N>::new();
+        c.insert_range(200..205);
+        assert_eq!(c.iter().collect::<Vec<_>>(), (200..205).collect::<Vec<_>>());
+
+        let mut d = IttyBitty::<N>::new();
+        d.insert_range(0..10);
+        d.toggle_range(5..15);
+        assert_eq!(
+            d.iter().collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 10, 11, 12, 13, 14]
+        );
+    }
+
+    #[test]
+    fn test_lazy_combinators<const N: usize>() {
+        let a = from_bits::<N>(&[1, 3, 200]);
+        let b = from_bits::<N>(&[3, 4, 500]);
+
+        assert_eq!(a.intersection(&b).collect::<Vec<_>>(), [3]);
+        assert_eq!(a.difference(&b).collect::<Vec<_>>(), [1, 200]);
+        assert_eq!(
+            a.symmetric_difference(&b).collect::<Vec<_>>(),
+            [1, 4, 200, 500]
+        );
+    }
+
+    #[test]
+    fn test_ord_hash<const N: usize>() {
+        use core::hash::{Hash, Hasher};
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            // a tiny FNV-1a so the test needs no std hasher
+            struct Fnv(u64);
+            impl Hasher for Fnv {
+                fn finish(&self) -> u64 {
+                    self.0
+                }
+                fn write(&mut self, bytes: &[u8]) {
+                    for &b in bytes {
+                        self.0 ^= b as u64;
+                        self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+                    }
+                }
+            }
+            let mut h = Fnv(0xcbf2_9ce4_8422_2325);
+            value.hash(&mut h);
+            h.finish()
+        }
+
+        // same set, one grown to heap then shrunk back: must compare and hash equal
+        let inline = from_bits::<N>(&[1, 5, 10]);
+        let mut grown = from_bits::<N>(&[1, 5, 10, 500]);
+        grown.set(500, false);
+        assert_eq!(inline, grown);
+        assert_eq!(inline.cmp(&grown), core::cmp::Ordering::Equal);
+        assert_eq!(hash_of(&inline), hash_of(&grown));
+
+        let a = from_bits::<N>(&[1, 2]);
+        let b = from_bits::<N>(&[300]);
+        assert!(a < b);
+        assert!(b > a);
+    }
+
     #[instantiate_tests(<2>)]
     mod n2 {}
     #[instantiate_tests(<3>)]